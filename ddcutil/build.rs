@@ -0,0 +1,109 @@
+use std::env;
+use std::ffi::CStr;
+use std::fmt::Write as _;
+use std::mem::MaybeUninit;
+use std::os::raw::c_char;
+use std::path::PathBuf;
+
+use ddcutil_sys as sys;
+
+/// MCCS versions whose feature metadata gets baked into the binary.
+const VERSIONS: &[(u8, u8)] = &[(2, 0), (2, 1), (3, 0), (2, 2)];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "fn static_feature_metadata(code: FeatureCode, major: u8, minor: u8) -> Option<StaticFeatureMetadata> {{"
+    ).unwrap();
+    writeln!(out, "    match (code, major, minor) {{").unwrap();
+
+    for code in 0u16..=0xFF {
+        for &(major, minor) in VERSIONS {
+            if let Some(entry) = query_metadata(code as u8, major, minor) {
+                let values: Vec<String> = entry
+                    .value_names
+                    .iter()
+                    .map(|(value_code, name)| format!("({value_code}, {name:?})"))
+                    .collect();
+
+                writeln!(
+                    out,
+                    "        ({code}, {major}, {minor}) => Some(StaticFeatureMetadata {{ \
+                     name: {name:?}, description: {description:?}, flags: {flags}, \
+                     value_names: &[{values}] }}),",
+                    code = code,
+                    major = major,
+                    minor = minor,
+                    name = entry.name,
+                    description = entry.description,
+                    flags = entry.flags,
+                    values = values.join(", "),
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::write(out_path.join("feature_metadata.rs"), out).unwrap();
+}
+
+struct RawMetadata {
+    name: String,
+    description: String,
+    flags: u16,
+    value_names: Vec<(u8, String)>,
+}
+
+/// Looks up a single feature/version combination via a live `libddcutil`
+/// call, returning `None` if the code is not defined for that version.
+fn query_metadata(code: u8, major: u8, minor: u8) -> Option<RawMetadata> {
+    unsafe {
+        let version = sys::DDCA_MCCS_Version_Spec { major, minor };
+        let mut meta = MaybeUninit::uninit();
+        let status =
+            sys::ddca_get_feature_metadata_by_vspec(code, version, false, meta.as_mut_ptr());
+        if status != 0 {
+            return None;
+        }
+        let meta = meta.assume_init();
+        let raw = &*meta;
+
+        let flags = raw.feature_flags;
+        let mut value_names = Vec::new();
+        if flags & sys::DDCA_SIMPLE_NC as u16 != 0 && !raw.sl_values.is_null() {
+            let mut ptr = raw.sl_values;
+            while (*ptr).value_code != 0 || !(*ptr).value_name.is_null() {
+                value_names.push(((*ptr).value_code, cstr_to_string((*ptr).value_name)));
+                ptr = ptr.offset(1);
+            }
+        }
+
+        let name = cstr_to_string(raw.feature_name);
+        let description = cstr_to_string(raw.feature_desc);
+
+        sys::ddca_free_feature_metadata(meta);
+
+        Some(RawMetadata {
+            name,
+            description,
+            flags,
+            value_names,
+        })
+    }
+}
+
+unsafe fn cstr_to_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
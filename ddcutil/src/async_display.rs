@@ -0,0 +1,126 @@
+use std::ffi::CString;
+use std::sync::mpsc;
+use std::thread;
+
+use futures::channel::oneshot;
+
+use crate::{Display, FeatureCode, FeatureMetadata, MccsVersion, Result, Value, VerificationError};
+
+type Job = Box<dyn FnOnce(&Display) + Send + 'static>;
+
+/// A non-blocking wrapper around [`Display`] that offloads every DDC/I2C
+/// round-trip onto a dedicated worker thread, returning a `Future` the
+/// caller can `.await` instead of blocking.
+///
+/// DDC is not reentrant per monitor, so all operations on the same
+/// `AsyncDisplay` are serialized through a single-threaded queue: each
+/// submitted call still costs its own blocking FFI round-trip, but queuing
+/// means the caller's thread (e.g. an event loop) never blocks on it.
+pub struct AsyncDisplay {
+    tx: Option<mpsc::Sender<Job>>,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl AsyncDisplay {
+    /// Takes ownership of `display` and starts its worker thread.
+    pub fn new(display: Display) -> Self {
+        let (tx, rx) = mpsc::channel::<Job>();
+        let worker = thread::spawn(move || {
+            let display = display;
+            for job in rx {
+                job(&display);
+            }
+        });
+
+        AsyncDisplay {
+            tx: Some(tx),
+            worker: Some(worker),
+        }
+    }
+
+    fn submit<T, F>(&self, f: F) -> oneshot::Receiver<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Display) -> T + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        let job: Job = Box::new(move |display| {
+            let _ = tx.send(f(display));
+        });
+
+        self.tx
+            .as_ref()
+            .expect("worker thread not running")
+            .send(job)
+            .expect("worker thread panicked");
+
+        rx
+    }
+
+    pub async fn capabilities_string(&self) -> Result<CString> {
+        self.submit(|display| display.capabilities_string())
+            .await
+            .expect("worker thread dropped response")
+    }
+
+    pub async fn vcp_get_value(&self, code: FeatureCode) -> Result<Value> {
+        self.submit(move |display| display.vcp_get_value(code))
+            .await
+            .expect("worker thread dropped response")
+    }
+
+    pub async fn vcp_set_value(&self, code: FeatureCode, value: u8) -> Result<()> {
+        self.submit(move |display| display.vcp_set_value(code, value))
+            .await
+            .expect("worker thread dropped response")
+    }
+
+    pub async fn vcp_get_table(&self, code: FeatureCode) -> Result<Vec<u8>> {
+        self.submit(move |display| display.vcp_get_table(code))
+            .await
+            .expect("worker thread dropped response")
+    }
+
+    pub async fn vcp_set_table(&self, code: FeatureCode, data: Vec<u8>) -> Result<()> {
+        self.submit(move |display| display.vcp_set_table(code, &data))
+            .await
+            .expect("worker thread dropped response")
+    }
+
+    pub async fn vcp_set_value_verify(
+        &self,
+        code: FeatureCode,
+        value: u8,
+    ) -> std::result::Result<Value, VerificationError> {
+        self.submit(move |display| display.vcp_set_value_verify(code, value))
+            .await
+            .expect("worker thread dropped response")
+    }
+
+    pub async fn vcp_set_value_u16(&self, code: FeatureCode, value: u16) -> Result<()> {
+        self.submit(move |display| display.vcp_set_value_u16(code, value))
+            .await
+            .expect("worker thread dropped response")
+    }
+
+    pub async fn vcp_get_feature_value(
+        &self,
+        code: FeatureCode,
+        version: MccsVersion,
+    ) -> Result<(Value, FeatureMetadata)> {
+        self.submit(move |display| display.vcp_get_feature_value(code, version))
+            .await
+            .expect("worker thread dropped response")
+    }
+}
+
+impl Drop for AsyncDisplay {
+    fn drop(&mut self) {
+        // Drop the sender first so the worker's `for job in rx` loop ends
+        // and the thread becomes joinable.
+        self.tx.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
@@ -3,7 +3,8 @@ use std::borrow::Cow;
 use std::ffi::{CStr, CString};
 use std::slice::from_raw_parts;
 use libc::{c_int, c_char};
-use crate::{sys, Error, Result, FeatureCode, Capabilities, Value};
+use crate::{sys, Error, Result, FeatureCode, Capabilities, FeatureMetadata, MccsVersion, Value};
+use crate::usb_class::UsbClass;
 
 #[derive(Clone)]
 pub struct DisplayInfo {
@@ -103,6 +104,22 @@ impl DisplayInfo {
     pub fn path(&self) -> DisplayPath {
         self.path
     }
+
+    /// For USB-path displays, returns the decoded USB class/subclass/protocol
+    /// of the backing hiddev interface, so callers can confirm a display is
+    /// reached over HID rather than working from opaque bus numbers.
+    ///
+    /// Returns `None` for non-USB displays, or if the interface descriptors
+    /// could not be read from sysfs.
+    pub fn usb_class(&self) -> Option<UsbClass> {
+        match self.path {
+            DisplayPath::Usb {
+                hiddev_device_number,
+                ..
+            } => UsbClass::from_hiddev(hiddev_device_number).ok(),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for DisplayInfo {
@@ -268,6 +285,38 @@ impl Display {
         }
     }
 
+    /// Sets a 16-bit continuous feature, writing both the low (SL) and high
+    /// (SH) byte, unlike [`Display::vcp_set_value`] which only ever writes
+    /// the low byte.
+    pub fn vcp_set_value_u16(&self, code: FeatureCode, value: u16) -> Result<()> {
+        unsafe {
+            Error::from_status(sys::ddca_set_non_table_vcp_value(
+                self.handle, code, (value & 0xff) as u8, (value >> 8) as u8
+            )).map(|_| ())
+        }
+    }
+
+    /// Sets a non-table VCP value, then re-reads it to confirm the monitor
+    /// actually applied the change. Returns the read-back [`Value`] on
+    /// success, or `Err(VerificationError::Mismatch(..))` if the monitor
+    /// reports a different value than what was written.
+    pub fn vcp_set_value_verify(
+        &self,
+        code: FeatureCode,
+        value: u8,
+    ) -> std::result::Result<Value, VerificationError> {
+        self.vcp_set_value(code, value)?;
+        let actual = self.vcp_get_value(code)?;
+        if actual.sl == value {
+            Ok(actual)
+        } else {
+            Err(VerificationError::Mismatch(VerificationMismatch {
+                expected: value,
+                actual,
+            }))
+        }
+    }
+
     pub fn vcp_get_value(&self, code: FeatureCode) -> Result<Value> {
         unsafe {
             let mut raw = mem::MaybeUninit::uninit();
@@ -295,11 +344,85 @@ impl Display {
         }
     }
 
+    pub fn vcp_set_table(&self, code: FeatureCode, data: &[u8]) -> Result<()> {
+        unsafe {
+            let mut value = sys::DDCA_Table_Value {
+                bytect: data.len() as _,
+                bytes: data.as_ptr() as *mut _,
+            };
+            Error::from_status(sys::ddca_set_table_vcp_value(
+                self.handle, code, &mut value
+            )).map(|_| ())
+        }
+    }
+
+    /// Reads a non-table VCP value together with its feature metadata, so
+    /// callers get the decoded SL value name for NC features in one call
+    /// instead of cross-referencing `FeatureMetadata` separately.
+    pub fn vcp_get_feature_value(
+        &self,
+        code: FeatureCode,
+        version: MccsVersion,
+    ) -> Result<(Value, FeatureMetadata)> {
+        let value = self.vcp_get_value(code)?;
+        let metadata = match FeatureMetadata::from_code_static(code, version) {
+            Some(metadata) => metadata,
+            None => FeatureMetadata::from_code(code, version)?,
+        };
+        Ok((value, metadata))
+    }
+
     pub fn raw(&self) -> sys::DDCA_Display_Handle {
         self.handle
     }
 }
 
+/// The monitor's read-back value did not match what [`Display::vcp_set_value_verify`]
+/// wrote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerificationMismatch {
+    pub expected: u8,
+    pub actual: Value,
+}
+
+impl fmt::Display for VerificationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected VCP value {}, monitor reports {}",
+            self.expected,
+            self.actual.value(),
+        )
+    }
+}
+
+impl std::error::Error for VerificationMismatch {}
+
+/// The error type of [`Display::vcp_set_value_verify`]: either the usual FFI
+/// [`Error`], or a successful write that the monitor did not actually apply.
+#[derive(Debug)]
+pub enum VerificationError {
+    Ffi(Error),
+    Mismatch(VerificationMismatch),
+}
+
+impl From<Error> for VerificationError {
+    fn from(error: Error) -> Self {
+        VerificationError::Ffi(error)
+    }
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VerificationError::Ffi(error) => fmt::Display::fmt(error, f),
+            VerificationError::Mismatch(mismatch) => fmt::Display::fmt(mismatch, f),
+        }
+    }
+}
+
+impl std::error::Error for VerificationError {}
+
 impl Drop for Display {
     fn drop(&mut self) {
         unsafe {
@@ -113,7 +113,39 @@ pub struct FeatureMetadata {
     pub flags: FeatureFlags,
 }
 
+/// A single `(code, version) -> metadata` entry in the generated offline
+/// table. Only used to seed [`FeatureMetadata::from_code_static`]; never
+/// exposed directly since its strings are `'static` and its `value_names`
+/// is a slice rather than a `HashMap`.
+struct StaticFeatureMetadata {
+    name: &'static str,
+    description: &'static str,
+    flags: u16,
+    value_names: &'static [(u8, &'static str)],
+}
+
+include!(concat!(env!("OUT_DIR"), "/feature_metadata.rs"));
+
 impl FeatureMetadata {
+    /// Looks up feature metadata from the table generated at build time by
+    /// `build.rs`, without a live `libddcutil` lookup or an open display.
+    ///
+    /// Returns `None` if `code` is not defined for `version` (metadata can
+    /// legitimately differ between MCCS versions, e.g. a code that is
+    /// `STD_CONT` in 2.0 may become `SIMPLE_NC` in 2.2).
+    pub fn from_code_static(code: FeatureCode, version: MccsVersion) -> Option<Self> {
+        static_feature_metadata(code, version.major, version.minor).map(|raw| FeatureMetadata {
+            name: raw.name.to_owned(),
+            description: raw.description.to_owned(),
+            value_names: raw
+                .value_names
+                .iter()
+                .map(|&(value_code, name)| (value_code, name.to_owned()))
+                .collect(),
+            flags: FeatureFlags::from_bits_truncate(raw.flags),
+        })
+    }
+
     pub fn from_code(code: FeatureCode, version: MccsVersion) -> Result<Self> {
         unsafe {
             let mut meta = mem::MaybeUninit::uninit();
@@ -246,3 +278,21 @@ impl FeatureFlags {
         self.is_nc() || self.is_cont() || self.is_table()
     }
 }
+
+#[test]
+fn test_from_code_static_known_feature() {
+    // Brightness (0x10) is defined in every MCCS version the offline table
+    // covers, so it should round-trip through the generated table as a
+    // continuous feature, without any FFI call or open display.
+    let version = MccsVersion { major: 2, minor: 0 };
+    let brightness = FeatureMetadata::from_code_static(0x10, version)
+        .expect("brightness (0x10) should be defined for MCCS 2.0");
+    assert!(brightness.flags.is_cont());
+}
+
+#[test]
+fn test_from_code_static_undefined_feature() {
+    // 0x00 is not a valid VCP feature code in any MCCS version.
+    let version = MccsVersion { major: 2, minor: 0 };
+    assert!(FeatureMetadata::from_code_static(0x00, version).is_none());
+}
@@ -0,0 +1,121 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The decoded USB class/subclass/protocol triple backing a `DisplayPath::Usb`,
+/// per the USB-IF base class descriptor table.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UsbClass {
+    Hid { boot: bool, protocol: HidBootProtocol },
+    Hub,
+    Audio(u8),
+    Mass(u8),
+    Comms(u8),
+    VendorSpecific(u8),
+    Other { class: u8, subclass: u8, protocol: u8 },
+}
+
+/// The boot protocol of a HID device, from `bInterfaceProtocol` when
+/// `bInterfaceSubClass` indicates a boot interface.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HidBootProtocol {
+    None,
+    Keyboard,
+    Mouse,
+}
+
+impl UsbClass {
+    /// Decodes a USB class/subclass/protocol triple as reported in an
+    /// interface descriptor.
+    pub fn from_triple(class: u8, subclass: u8, protocol: u8) -> Self {
+        match class {
+            0x01 => UsbClass::Audio(subclass),
+            0x02 | 0x0a => UsbClass::Comms(subclass),
+            0x03 => UsbClass::Hid {
+                boot: subclass == 0x01,
+                protocol: match (subclass, protocol) {
+                    (0x01, 0x01) => HidBootProtocol::Keyboard,
+                    (0x01, 0x02) => HidBootProtocol::Mouse,
+                    _ => HidBootProtocol::None,
+                },
+            },
+            0x08 => UsbClass::Mass(subclass),
+            0x09 => UsbClass::Hub,
+            0xff => UsbClass::VendorSpecific(subclass),
+            _ => UsbClass::Other {
+                class,
+                subclass,
+                protocol,
+            },
+        }
+    }
+
+    /// Reads the class/subclass/protocol triple of the USB interface behind
+    /// a `/dev/usb/hiddevN` node from its sysfs descriptor files.
+    pub fn from_hiddev(hiddev_device_number: i32) -> io::Result<Self> {
+        let base =
+            PathBuf::from(format!("/sys/class/usbmisc/hiddev{hiddev_device_number}/device"));
+        let class = read_hex_byte(&base.join("bInterfaceClass"))?;
+        let subclass = read_hex_byte(&base.join("bInterfaceSubClass"))?;
+        let protocol = read_hex_byte(&base.join("bInterfaceProtocol"))?;
+        Ok(Self::from_triple(class, subclass, protocol))
+    }
+}
+
+fn read_hex_byte(path: &Path) -> io::Result<u8> {
+    let contents = fs::read_to_string(path)?;
+    u8::from_str_radix(contents.trim().trim_start_matches("0x"), 16)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[test]
+fn test_from_triple_hid_boot_keyboard() {
+    assert_eq!(
+        UsbClass::from_triple(0x03, 0x01, 0x01),
+        UsbClass::Hid {
+            boot: true,
+            protocol: HidBootProtocol::Keyboard,
+        },
+    );
+}
+
+#[test]
+fn test_from_triple_hid_non_boot() {
+    assert_eq!(
+        UsbClass::from_triple(0x03, 0x00, 0x00),
+        UsbClass::Hid {
+            boot: false,
+            protocol: HidBootProtocol::None,
+        },
+    );
+}
+
+#[test]
+fn test_from_triple_hub() {
+    assert_eq!(UsbClass::from_triple(0x09, 0x00, 0x00), UsbClass::Hub);
+}
+
+#[test]
+fn test_from_triple_mass_storage() {
+    assert_eq!(UsbClass::from_triple(0x08, 0x06, 0x50), UsbClass::Mass(0x06));
+}
+
+#[test]
+fn test_from_triple_vendor_specific() {
+    assert_eq!(
+        UsbClass::from_triple(0xff, 0x42, 0x01),
+        UsbClass::VendorSpecific(0x42),
+    );
+}
+
+#[test]
+fn test_from_triple_unknown_class() {
+    assert_eq!(
+        UsbClass::from_triple(0x7a, 0x01, 0x02),
+        UsbClass::Other {
+            class: 0x7a,
+            subclass: 0x01,
+            protocol: 0x02,
+        },
+    );
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+use std::mem;
+use std::sync::mpsc;
+use std::sync::{Mutex, OnceLock};
+
+use crate::{sys, DisplayInfo, DisplayPath, Error};
+
+/// A change in display topology or power state, delivered by [`DisplayWatcher`].
+#[derive(Debug, Clone)]
+pub enum DisplayEvent {
+    /// A display was connected.
+    Connected(DisplayInfo),
+    /// A display was disconnected. Only the path survives, since the
+    /// display can no longer be queried once it is gone.
+    ///
+    /// `libddcutil`'s native disconnect event carries no USB bus/device
+    /// numbers, so for a `DisplayPath::Usb` path those two fields are
+    /// reported as the sentinel `-1` rather than a fabricated `0`; only
+    /// `hiddev_device_number` is real.
+    Disconnected(DisplayPath),
+    /// A display's DPMS/power state changed.
+    Changed(DisplayInfo),
+}
+
+static EVENT_TX: OnceLock<Mutex<Option<mpsc::Sender<DisplayEvent>>>> = OnceLock::new();
+
+fn event_tx() -> &'static Mutex<Option<mpsc::Sender<DisplayEvent>>> {
+    EVENT_TX.get_or_init(|| Mutex::new(None))
+}
+
+extern "C" fn watch_callback(event: sys::DDCA_Display_Status_Event) {
+    let guard = event_tx().lock().unwrap();
+    let Some(tx) = guard.as_ref() else {
+        return;
+    };
+
+    let mapped = match event.event_type {
+        sys::DDCA_Display_Event_Type_DDCA_EVENT_DISPLAY_DISCONNECTED => unsafe {
+            // Bus/device numbers aren't known for a disconnect event; use
+            // the same kind of sentinel fallback as `DisplayInfo::from_raw`
+            // rather than fabricating plausible-looking zeros.
+            DisplayPath::from_raw(&event.io_path, -1, -1)
+                .ok()
+                .map(DisplayEvent::Disconnected)
+        },
+        event_type => unsafe {
+            let mut info = mem::MaybeUninit::uninit();
+            let status = sys::ddca_get_display_info(event.dref, info.as_mut_ptr());
+            Error::from_status(status).ok().and_then(|_| {
+                let info = DisplayInfo::from_raw(&*info.assume_init());
+                match event_type {
+                    sys::DDCA_Display_Event_Type_DDCA_EVENT_DISPLAY_CONNECTED => {
+                        Some(DisplayEvent::Connected(info))
+                    }
+                    sys::DDCA_Display_Event_Type_DDCA_EVENT_DPMS_AWAKE
+                    | sys::DDCA_Display_Event_Type_DDCA_EVENT_DPMS_ASLEEP => {
+                        Some(DisplayEvent::Changed(info))
+                    }
+                    _ => None,
+                }
+            })
+        },
+    };
+
+    if let Some(event) = mapped {
+        let _ = tx.send(event);
+    }
+}
+
+/// Watches for display connect/disconnect/DPMS events in the background and
+/// delivers them as a [`DisplayEvent`] stream, so long-running apps don't
+/// have to poll `DisplayInfo::enumerate` and can re-run capability
+/// negotiation only when something actually changed.
+///
+/// Only one `DisplayWatcher` may be active per process at a time, mirroring
+/// `libddcutil`'s single global watch thread. Once a `DisplayWatcher` is
+/// dropped, a new one may be started.
+pub struct DisplayWatcher {
+    rx: mpsc::Receiver<DisplayEvent>,
+}
+
+impl DisplayWatcher {
+    /// Registers the event callback and starts `libddcutil`'s background
+    /// watch thread.
+    ///
+    /// Returns `Err(WatchError::AlreadyRunning)` if a `DisplayWatcher` is
+    /// already running in this process; this is an ordinary runtime
+    /// condition (e.g. two independent subsystems each calling `start()`),
+    /// not a programming error, so it is reported rather than panicking.
+    pub fn start() -> std::result::Result<Self, WatchError> {
+        let mut guard = event_tx().lock().unwrap();
+        if guard.is_some() {
+            return Err(WatchError::AlreadyRunning);
+        }
+
+        let (tx, rx) = mpsc::channel();
+
+        // Only latch the singleton guard once both FFI calls have
+        // succeeded, so a failed `start()` leaves the watcher restartable
+        // rather than permanently wedging future calls.
+        unsafe {
+            Error::from_status(sys::ddca_register_display_status_callback(Some(
+                watch_callback,
+            )))?;
+            Error::from_status(sys::ddca_start_watch_displays(
+                sys::DDCA_Display_Event_Class_DDCA_EVENT_CLASS_ALL,
+            ))?;
+        }
+
+        *guard = Some(tx);
+        drop(guard);
+
+        Ok(DisplayWatcher { rx })
+    }
+
+    /// Blocks until the next display event arrives.
+    pub fn recv(&self) -> Option<DisplayEvent> {
+        self.rx.recv().ok()
+    }
+
+    /// Drains and returns all display events received so far without blocking.
+    pub fn try_iter(&self) -> mpsc::TryIter<DisplayEvent> {
+        self.rx.try_iter()
+    }
+}
+
+impl Drop for DisplayWatcher {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = sys::ddca_stop_watch_displays(true);
+        }
+        // Clear the guard so a subsequent `DisplayWatcher::start()` in this
+        // process is allowed to run again.
+        *event_tx().lock().unwrap() = None;
+    }
+}
+
+/// The error type of [`DisplayWatcher::start`]: either the usual FFI
+/// [`Error`], or an attempt to start a second watcher while one is already
+/// running.
+#[derive(Debug)]
+pub enum WatchError {
+    Ffi(Error),
+    AlreadyRunning,
+}
+
+impl From<Error> for WatchError {
+    fn from(error: Error) -> Self {
+        WatchError::Ffi(error)
+    }
+}
+
+impl fmt::Display for WatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WatchError::Ffi(error) => fmt::Display::fmt(error, f),
+            WatchError::AlreadyRunning => {
+                write!(f, "a DisplayWatcher is already running in this process")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WatchError {}